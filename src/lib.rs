@@ -1,9 +1,9 @@
-use std::env;
+use std::{env, fs, path::PathBuf};
 
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
-    Lit, Result, Token,
+    Expr, Result, Token,
     parse::{Parse, ParseStream},
     parse_macro_input,
 };
@@ -17,7 +17,8 @@ enum WLBrand {
 // a block similar to a match arm
 struct WLMatch {
     brand: WLBrand,
-    literal: Lit,
+    literal: Expr,
+    span: proc_macro2::Span,
 }
 
 // a sequence of literal/wildcard matches
@@ -39,10 +40,16 @@ impl Parse for WLBrand {
 
 impl Parse for WLMatch {
     fn parse(input: ParseStream) -> Result<Self> {
+        // the span of the arm's first token (its brand pattern), for per-arm diagnostics
+        let span = input.span();
         let brand = input.parse()?;
         input.parse::<Token![=>]>()?;
         let literal = input.parse()?;
-        Ok(WLMatch { brand, literal })
+        Ok(WLMatch {
+            brand,
+            literal,
+            span,
+        })
     }
 }
 
@@ -61,6 +68,138 @@ impl Parse for WLInput {
     }
 }
 
+/// Reads the project-level brand manifest, if the calling crate has one.
+///
+/// The manifest lives at `brands.toml` next to the crate's `Cargo.toml` and
+/// declares the complete, closed set of legal brand names:
+///
+/// ```toml
+/// brands = ["Northwind", "Contoso"]
+/// ```
+///
+/// Crates without a `brands.toml` are unaffected - `brand!` falls back to its
+/// unchecked, free-form matching. A `brands.toml` that *does* exist but can't be
+/// read or doesn't have the expected shape is a compile error rather than a
+/// silent fallback, so a broken manifest can't masquerade as "no manifest".
+fn read_brand_manifest() -> Result<Option<Vec<String>>> {
+    let Some(manifest_dir) = env::var("CARGO_MANIFEST_DIR").ok() else {
+        return Ok(None);
+    };
+    let path = PathBuf::from(manifest_dir).join("brands.toml");
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let manifest_error = |message: String| {
+        syn::Error::new(proc_macro2::Span::call_site(), message)
+    };
+
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| manifest_error(format!("failed to read {}: {e}", path.display())))?;
+    let value: toml::Value = contents
+        .parse()
+        .map_err(|e| manifest_error(format!("failed to parse {}: {e}", path.display())))?;
+    let brands = value
+        .get("brands")
+        .and_then(|b| b.as_array())
+        .ok_or_else(|| {
+            manifest_error(format!(
+                "{} must have a top-level `brands = [...]` array of strings",
+                path.display()
+            ))
+        })?;
+    brands
+        .iter()
+        .map(|b| {
+            b.as_str().map(str::to_owned).ok_or_else(|| {
+                manifest_error(format!(
+                    "{} `brands` array must contain only strings, found {b}",
+                    path.display()
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Cross-checks `matches` against the declared `brands`, catching drift between
+/// the manifest and the macro's call sites.
+fn check_brand_manifest(matches: &[WLMatch], brands: &[String]) -> Result<()> {
+    // typo protection: every named arm must name a brand that's actually declared
+    for m in matches {
+        if let WLBrand::Named(s) = &m.brand {
+            if !brands.contains(s) {
+                return Err(syn::Error::new(
+                    m.span,
+                    format!(
+                        "brand `{s}` is not declared in brands.toml; declared brands are {}",
+                        join_quoted(brands)
+                    ),
+                ));
+            }
+        }
+    }
+
+    // missing-coverage protection: every declared brand needs an arm, unless there's a wildcard
+    let has_wildcard = matches.iter().any(|m| matches!(m.brand, WLBrand::Wildcard));
+    if !has_wildcard {
+        let missing: Vec<&String> = brands
+            .iter()
+            .filter(|b| !matches.iter().any(|m| matches!(&m.brand, WLBrand::Named(s) if s == *b)))
+            .collect();
+        if !missing.is_empty() {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!(
+                    "brands.toml declares brands with no matching arm and no `_` wildcard: {}",
+                    join_quoted(&missing)
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Analyses `matches` for the same reachability guarantees a real `match` gives:
+/// no two arms handle the same brand, and nothing follows a `_` wildcard.
+fn check_reachability(matches: &[WLMatch]) -> Result<()> {
+    let mut seen = Vec::new();
+    let mut seen_wildcard = false;
+
+    for m in matches {
+        if seen_wildcard {
+            return Err(syn::Error::new(
+                m.span,
+                "unreachable arm: this arm comes after a `_` wildcard and will never be selected",
+            ));
+        }
+
+        match &m.brand {
+            WLBrand::Named(s) => {
+                if seen.contains(&s.as_str()) {
+                    return Err(syn::Error::new(
+                        m.span,
+                        format!("duplicate arm: brand `{s}` is already handled by an earlier arm"),
+                    ));
+                }
+                seen.push(s.as_str());
+            }
+            WLBrand::Wildcard => seen_wildcard = true,
+        }
+    }
+
+    Ok(())
+}
+
+fn join_quoted<S: AsRef<str>>(items: &[S]) -> String {
+    items
+        .iter()
+        .map(|s| format!("`{}`", s.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Compile-time brand selection macro for white-label builds.
 ///
 /// This macro reads the `WHITE_LABEL_BRAND` environment variable at compile time
@@ -98,13 +237,27 @@ impl Parse for WLInput {
 ///     "Northwind" => 8080,
 ///     "Contoso" => 9090,
 /// };
+///
+/// // Any const-evaluable expression, not just literals
+/// struct Theme {
+///     primary: &'static str,
+/// }
+/// const THEME: Theme = brand! {
+///     "Northwind" => Theme { primary: "#003087" },
+///     "Contoso" => Theme { primary: "#e81123" },
+///     _ => Theme { primary: "#000000" },
+/// };
 /// ```
 ///
-/// # Panics
+/// # Errors
 ///
-/// Panics at compile time if:
+/// Emits a compile error, spanned to the macro invocation or the offending arm, if:
 /// - `WHITE_LABEL_BRAND` environment variable is not set
-/// - The brand value doesn't match any of the provided patterns and no wildcard is present
+/// - The brand value doesn't match any of the provided patterns and no wildcard is present,
+///   in which case the message names the unhandled value and lists the declared arms
+/// - A `brands.toml` manifest is present and an arm names a brand it doesn't declare, or
+///   declares a brand that no arm (and no `_` wildcard) covers
+/// - Two arms name the same brand, or an arm follows a `_` wildcard and can never run
 ///
 /// # Environment Variable
 ///
@@ -115,31 +268,365 @@ impl Parse for WLInput {
 /// ```
 #[proc_macro]
 pub fn brand(input: TokenStream) -> TokenStream {
+    // keep a copy of the invocation tokens around so errors can point back at the call site
+    let call_site: proc_macro2::TokenStream = input.clone().into();
     let parsed_input = parse_macro_input!(input as WLInput);
 
-    // check all the match arms against the environment variable value and exit early if matched
-    if let Ok(env_value) = env::var("WHITE_LABEL_BRAND") {
-        for WLMatch { brand, literal } in parsed_input.matches {
+    if let Err(err) = check_reachability(&parsed_input.matches) {
+        return err.to_compile_error().into();
+    }
+
+    match read_brand_manifest() {
+        Ok(Some(brands)) => {
+            if let Err(err) = check_brand_manifest(&parsed_input.matches, &brands) {
+                return err.to_compile_error().into();
+            }
+        }
+        Ok(None) => {}
+        Err(err) => return err.to_compile_error().into(),
+    }
+
+    let env_value = match env::var("WHITE_LABEL_BRAND") {
+        Ok(v) => v,
+        Err(_) => {
+            return syn::Error::new_spanned(call_site, "WHITE_LABEL_BRAND must be set.")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    // check all the match arms against the environment variable value and exit early if matched,
+    // remembering the named arms in case nothing matches and we need to report them
+    let mut named = Vec::new();
+    for WLMatch { brand, literal, .. } in parsed_input.matches {
+        match brand {
+            WLBrand::Named(s) if s == env_value => {
+                return quote!(#literal).into();
+            }
+            WLBrand::Named(s) => named.push(s),
+            WLBrand::Wildcard => {
+                return quote!(#literal).into();
+            }
+        }
+    }
+
+    syn::Error::new_spanned(
+        call_site,
+        format!(
+            "brand `{env_value}` not handled; arms are {}",
+            join_quoted(&named)
+        ),
+    )
+    .to_compile_error()
+    .into()
+}
+
+mod kw {
+    syn::custom_keyword!(axes);
+    syn::custom_keyword!(not);
+}
+
+// a parenthesized tuple of brand/wildcard patterns, one per axis: ("foo", _, "bar")
+struct WLMatrixKey {
+    brands: Vec<WLBrand>,
+}
+
+// a block similar to a match arm, keyed on a tuple instead of a single brand
+struct WLMatrixMatch {
+    key: WLMatrixKey,
+    literal: Expr,
+    span: proc_macro2::Span,
+}
+
+// an `axes: [...]` header followed by a sequence of tuple/wildcard matches
+struct WLMatrixInput {
+    axes: Vec<String>,
+    matches: Vec<WLMatrixMatch>,
+}
+
+impl Parse for WLMatrixKey {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let brands = content.parse_terminated(WLBrand::parse, Token![,])?;
+        Ok(WLMatrixKey {
+            brands: brands.into_iter().collect(),
+        })
+    }
+}
+
+impl Parse for WLMatrixMatch {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let span = input.span();
+        let key = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let literal = input.parse()?;
+        Ok(WLMatrixMatch { key, literal, span })
+    }
+}
+
+impl Parse for WLMatrixInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<kw::axes>()?;
+        input.parse::<Token![:]>()?;
+        let content;
+        syn::bracketed!(content in input);
+        let axis_lits: syn::punctuated::Punctuated<syn::LitStr, Token![,]> =
+            content.parse_terminated(<syn::LitStr as Parse>::parse, Token![,])?;
+        let axes = axis_lits.into_iter().map(|lit| lit.value()).collect();
+        input.parse::<Token![,]>()?;
+
+        let mut matches = Vec::new();
+        while !input.is_empty() {
+            matches.push(input.parse::<WLMatrixMatch>()?);
+
+            // optional trailing comma
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        Ok(WLMatrixInput { axes, matches })
+    }
+}
+
+/// Compile-time multi-axis brand selection, for configuration that varies on more
+/// than one dimension at once (for example brand *and* deployment tier).
+///
+/// `brand_matrix!` reads one environment variable per declared axis and walks its
+/// arms top to bottom, matching each tuple position against the corresponding
+/// variable; `_` matches anything. The first arm that matches on every position
+/// wins, so more specific arms should come before more general ones - exactly
+/// like a regular Rust `match`.
+///
+/// # Syntax
+///
+/// ```ignore
+/// brand_matrix! {
+///     axes: ["ENV_VAR_ONE", "ENV_VAR_TWO"],
+///     ("Value1", "Value2") => value,
+///     ("Value1", _) => other_value,
+///     (_, _) => default_value,
+/// }
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use white_label::brand_matrix;
+///
+/// const ENDPOINT: &str = brand_matrix! {
+///     axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+///     ("Northwind", "prod") => "https://northwind.example.com/",
+///     ("Northwind", _) => "https://northwind.staging.example.com/",
+///     (_, _) => "https://default.example.com/",
+/// };
+/// ```
+///
+/// # Errors
+///
+/// Emits a compile error, spanned to the macro invocation or the offending arm, if:
+/// - An arm's tuple doesn't have exactly as many positions as there are declared axes
+/// - Two arms have an identical tuple, or an arm follows a fully wildcarded `(_, _, ...)`
+///   catch-all and can never run - the same reachability guarantees `brand!` gives
+/// - No arm matches the combination of values the axis environment variables hold
+///   (unset variables never match a `Named` position) and no `(_, _, ...)` catch-all is present
+#[proc_macro]
+pub fn brand_matrix(input: TokenStream) -> TokenStream {
+    let call_site: proc_macro2::TokenStream = input.clone().into();
+    let parsed_input = parse_macro_input!(input as WLMatrixInput);
+
+    if let Err(err) = check_matrix_arity(&parsed_input.matches, parsed_input.axes.len()) {
+        return err.to_compile_error().into();
+    }
+
+    if let Err(err) = check_matrix_reachability(&parsed_input.matches) {
+        return err.to_compile_error().into();
+    }
+
+    let env_values: Vec<Option<String>> = parsed_input
+        .axes
+        .iter()
+        .map(|axis| env::var(axis).ok())
+        .collect();
+
+    'arms: for WLMatrixMatch { key, literal, .. } in parsed_input.matches {
+        for (position, brand) in key.brands.iter().enumerate() {
             match brand {
-                WLBrand::Named(s) if s == env_value => {
-                    return quote!(#literal).into();
-                }
-                WLBrand::Wildcard => {
-                    return quote!(#literal).into();
-                }
-                _ => continue,
+                WLBrand::Named(s) if env_values[position].as_deref() == Some(s.as_str()) => {}
+                WLBrand::Wildcard => {}
+                _ => continue 'arms,
             }
         }
+        return quote!(#literal).into();
     }
 
-    panic!("WHITE_LABEL_BRAND must be set.")
+    no_matrix_arm_matched_error(call_site, &parsed_input.axes, &env_values)
+        .to_compile_error()
+        .into()
+}
+
+/// Checks that every arm's tuple has exactly as many positions as there are declared axes.
+fn check_matrix_arity(matches: &[WLMatrixMatch], axis_count: usize) -> Result<()> {
+    for m in matches {
+        if m.key.brands.len() != axis_count {
+            return Err(syn::Error::new(
+                m.span,
+                format!(
+                    "expected a {axis_count}-tuple matching the declared axes, found {}",
+                    m.key.brands.len()
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Analyses `matches` for the same reachability guarantees `check_reachability` gives
+/// `brand!`: no two arms have an identical tuple, and nothing follows a fully
+/// wildcarded `(_, _, ...)` catch-all.
+fn check_matrix_reachability(matches: &[WLMatrixMatch]) -> Result<()> {
+    let mut seen: Vec<Vec<Option<&str>>> = Vec::new();
+
+    for m in matches {
+        let key: Vec<Option<&str>> = m
+            .key
+            .brands
+            .iter()
+            .map(|b| match b {
+                WLBrand::Named(s) => Some(s.as_str()),
+                WLBrand::Wildcard => None,
+            })
+            .collect();
+
+        if let Some(seen_key) = seen.last() {
+            if seen_key.iter().all(Option::is_none) {
+                return Err(syn::Error::new(
+                    m.span,
+                    "unreachable arm: this arm comes after a fully wildcarded `(_, _, ...)` catch-all and will never be selected",
+                ));
+            }
+        }
+
+        if seen.contains(&key) {
+            return Err(syn::Error::new(
+                m.span,
+                "duplicate arm: this tuple is already handled by an earlier arm",
+            ));
+        }
+        seen.push(key);
+    }
+
+    Ok(())
+}
+
+/// Builds the "no arm matched" error, reporting each axis alongside the value its
+/// environment variable held (or `<unset>`).
+fn no_matrix_arm_matched_error(
+    call_site: proc_macro2::TokenStream,
+    axes: &[String],
+    env_values: &[Option<String>],
+) -> syn::Error {
+    let observed = axes
+        .iter()
+        .zip(env_values.iter())
+        .map(|(axis, v)| format!("{axis}={}", v.as_deref().unwrap_or("<unset>")))
+        .collect::<Vec<_>>()
+        .join(", ");
+    syn::Error::new_spanned(call_site, format!("no arm matched ({observed})"))
+}
+
+// the brand list in `#[brand_cfg(...)]`, with an optional leading `not(...)` negation
+struct WLBrandCfg {
+    negated: bool,
+    brands: Vec<String>,
+}
+
+impl Parse for WLBrandCfg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(kw::not) {
+            input.parse::<kw::not>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            Ok(WLBrandCfg {
+                negated: true,
+                brands: parse_brand_names(&content)?,
+            })
+        } else {
+            Ok(WLBrandCfg {
+                negated: false,
+                brands: parse_brand_names(input)?,
+            })
+        }
+    }
+}
+
+fn parse_brand_names(input: ParseStream) -> Result<Vec<String>> {
+    let lits = input.parse_terminated(<syn::LitStr as Parse>::parse, Token![,])?;
+    Ok(lits.into_iter().map(|lit| lit.value()).collect())
+}
+
+/// Conditionally compiles an item based on the compile-time brand, the way `#[cfg(...)]`
+/// conditionally compiles on platform or feature - but keyed on `WHITE_LABEL_BRAND` and
+/// reusing the same brand-name parsing as [`brand!`](macro@brand) and
+/// [`brand_matrix!`](macro@brand_matrix).
+///
+/// It can't be named `#[brand(...)]`, because a proc-macro crate may only export one
+/// macro per name and `brand!` already claims it; `brand_cfg` is the attribute form.
+///
+/// # Syntax
+///
+/// ```ignore
+/// #[brand_cfg("Northwind", "Contoso")]
+/// // kept only when WHITE_LABEL_BRAND is "Northwind" or "Contoso"
+///
+/// #[brand_cfg(not("Northwind"))]
+/// // kept for every brand except "Northwind"
+/// ```
+///
+/// # Examples
+///
+/// ```ignore
+/// use white_label::brand_cfg;
+///
+/// #[brand_cfg("Northwind")]
+/// fn northwind_only_feature() {}
+///
+/// #[brand_cfg(not("Northwind"))]
+/// mod everyone_else {}
+/// ```
+///
+/// # Errors
+///
+/// Emits a compile error if `WHITE_LABEL_BRAND` is not set; an unset brand can never
+/// satisfy a positive brand list, but it also can't be reliably said to satisfy a
+/// `not(...)` one, so both forms require the environment variable to be present.
+#[proc_macro_attribute]
+pub fn brand_cfg(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let cfg = parse_macro_input!(attr as WLBrandCfg);
+
+    let env_value = match env::var("WHITE_LABEL_BRAND") {
+        Ok(v) => v,
+        Err(_) => {
+            return syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "WHITE_LABEL_BRAND must be set.",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let matched = cfg.brands.contains(&env_value);
+    let keep = matched != cfg.negated;
+
+    if keep { item } else { TokenStream::new() }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use quote::quote;
-    use syn::parse2;
+    use syn::{ExprLit, Lit, parse2};
 
     #[test]
     fn test_parse_brand_named() {
@@ -163,6 +650,14 @@ mod tests {
         }
     }
 
+    // unwrap the `Lit` out of an `Expr::Lit`, as arms still produce literals most of the time
+    fn expect_lit(expr: Expr) -> Lit {
+        match expr {
+            Expr::Lit(ExprLit { lit, .. }) => lit,
+            other => panic!("Expected a literal expression, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_parse_literal_string() {
         let input = quote! { "Northwind" => "https://northwind.example.com/" };
@@ -173,7 +668,7 @@ mod tests {
             WLBrand::Wildcard => panic!("Expected Named brand"),
         }
 
-        match wl_match.literal {
+        match expect_lit(wl_match.literal) {
             Lit::Str(s) => assert_eq!(s.value(), "https://northwind.example.com/"),
             _ => panic!("Expected string literal"),
         }
@@ -184,7 +679,7 @@ mod tests {
         let input = quote! { "Development" => true };
         let wl_match: WLMatch = parse2(input).unwrap();
 
-        match wl_match.literal {
+        match expect_lit(wl_match.literal) {
             Lit::Bool(b) => assert!(b.value),
             _ => panic!("Expected bool literal"),
         }
@@ -195,7 +690,7 @@ mod tests {
         let input = quote! { "Northwind" => 8080 };
         let wl_match: WLMatch = parse2(input).unwrap();
 
-        match wl_match.literal {
+        match expect_lit(wl_match.literal) {
             Lit::Int(i) => assert_eq!(i.base10_parse::<u32>().unwrap(), 8080),
             _ => panic!("Expected int literal"),
         }
@@ -206,7 +701,7 @@ mod tests {
         let input = quote! { "Northwind" => 1.5 };
         let wl_match: WLMatch = parse2(input).unwrap();
 
-        match wl_match.literal {
+        match expect_lit(wl_match.literal) {
             Lit::Float(f) => assert_eq!(f.base10_parse::<f64>().unwrap(), 1.5),
             _ => panic!("Expected float literal"),
         }
@@ -217,12 +712,34 @@ mod tests {
         let input = quote! { "Northwind" => 'N' };
         let wl_match: WLMatch = parse2(input).unwrap();
 
-        match wl_match.literal {
+        match expect_lit(wl_match.literal) {
             Lit::Char(c) => assert_eq!(c.value(), 'N'),
             _ => panic!("Expected char literal"),
         }
     }
 
+    #[test]
+    fn test_parse_expr_struct_literal() {
+        let input = quote! { "Northwind" => Theme { primary: "#003087" } };
+        let wl_match: WLMatch = parse2(input).unwrap();
+
+        match wl_match.literal {
+            Expr::Struct(s) => assert!(s.path.is_ident("Theme")),
+            other => panic!("Expected struct literal, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_expr_array() {
+        let input = quote! { "Northwind" => ["a", "b"] };
+        let wl_match: WLMatch = parse2(input).unwrap();
+
+        match wl_match.literal {
+            Expr::Array(a) => assert_eq!(a.elems.len(), 2),
+            other => panic!("Expected array expression, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_parse_single_match() {
         let input = quote! { _ => "always" };
@@ -276,4 +793,209 @@ mod tests {
 
         assert_eq!(wl_input.matches.len(), 2);
     }
+
+    #[test]
+    fn test_manifest_check_passes_when_arms_match_manifest() {
+        let input = quote! {
+            "Northwind" => "value1",
+            "Contoso" => "value2",
+        };
+        let wl_input: WLInput = parse2(input).unwrap();
+        let brands = vec!["Northwind".to_string(), "Contoso".to_string()];
+
+        assert!(check_brand_manifest(&wl_input.matches, &brands).is_ok());
+    }
+
+    #[test]
+    fn test_manifest_check_passes_with_wildcard_covering_gaps() {
+        let input = quote! {
+            "Northwind" => "value1",
+            _ => "default",
+        };
+        let wl_input: WLInput = parse2(input).unwrap();
+        let brands = vec!["Northwind".to_string(), "Contoso".to_string()];
+
+        assert!(check_brand_manifest(&wl_input.matches, &brands).is_ok());
+    }
+
+    #[test]
+    fn test_manifest_check_rejects_undeclared_arm() {
+        let input = quote! {
+            "Acme" => "value1",
+            _ => "default",
+        };
+        let wl_input: WLInput = parse2(input).unwrap();
+        let brands = vec!["Northwind".to_string(), "Contoso".to_string()];
+
+        let err = check_brand_manifest(&wl_input.matches, &brands).unwrap_err();
+        assert!(err.to_string().contains("Acme"));
+    }
+
+    #[test]
+    fn test_manifest_check_rejects_missing_coverage() {
+        let input = quote! {
+            "Northwind" => "value1",
+        };
+        let wl_input: WLInput = parse2(input).unwrap();
+        let brands = vec!["Northwind".to_string(), "Contoso".to_string()];
+
+        let err = check_brand_manifest(&wl_input.matches, &brands).unwrap_err();
+        assert!(err.to_string().contains("Contoso"));
+    }
+
+    #[test]
+    fn test_reachability_passes_for_distinct_arms() {
+        let input = quote! {
+            "Northwind" => "value1",
+            "Contoso" => "value2",
+            _ => "default",
+        };
+        let wl_input: WLInput = parse2(input).unwrap();
+
+        assert!(check_reachability(&wl_input.matches).is_ok());
+    }
+
+    #[test]
+    fn test_reachability_rejects_duplicate_arm() {
+        let input = quote! {
+            "Northwind" => "value1",
+            "Northwind" => "value2",
+        };
+        let wl_input: WLInput = parse2(input).unwrap();
+
+        let err = check_reachability(&wl_input.matches).unwrap_err();
+        assert!(err.to_string().contains("duplicate arm"));
+    }
+
+    #[test]
+    fn test_reachability_rejects_arm_after_wildcard() {
+        let input = quote! {
+            _ => "default",
+            "Northwind" => "value1",
+        };
+        let wl_input: WLInput = parse2(input).unwrap();
+
+        let err = check_reachability(&wl_input.matches).unwrap_err();
+        assert!(err.to_string().contains("unreachable arm"));
+    }
+
+    #[test]
+    fn test_parse_matrix_input() {
+        let input = quote! {
+            axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+            ("Northwind", "prod") => "a",
+            ("Northwind", _) => "b",
+            (_, _) => "c",
+        };
+        let wl_input: WLMatrixInput = parse2(input).unwrap();
+
+        assert_eq!(wl_input.axes, vec!["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"]);
+        assert_eq!(wl_input.matches.len(), 3);
+        assert_eq!(wl_input.matches[0].key.brands.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_matrix_key_mixes_named_and_wildcard() {
+        let input = quote! { ("Northwind", _) };
+        let key: WLMatrixKey = parse2(input).unwrap();
+
+        match &key.brands[0] {
+            WLBrand::Named(s) => assert_eq!(s, "Northwind"),
+            WLBrand::Wildcard => panic!("Expected Named brand"),
+        }
+        match &key.brands[1] {
+            WLBrand::Wildcard => (),
+            WLBrand::Named(_) => panic!("Expected Wildcard"),
+        }
+    }
+
+    #[test]
+    fn test_matrix_rejects_wrong_arity() {
+        let input = quote! {
+            axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+            ("Northwind") => "a",
+        };
+        let wl_input: WLMatrixInput = parse2(input).unwrap();
+
+        let err = check_matrix_arity(&wl_input.matches, wl_input.axes.len()).unwrap_err();
+        assert!(err.to_string().contains("2-tuple"));
+    }
+
+    #[test]
+    fn test_matrix_reachability_passes_for_distinct_arms() {
+        let input = quote! {
+            axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+            ("Northwind", "prod") => "a",
+            ("Northwind", _) => "b",
+            (_, _) => "c",
+        };
+        let wl_input: WLMatrixInput = parse2(input).unwrap();
+
+        assert!(check_matrix_reachability(&wl_input.matches).is_ok());
+    }
+
+    #[test]
+    fn test_matrix_reachability_rejects_duplicate_arm() {
+        let input = quote! {
+            axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+            ("Northwind", "prod") => "a",
+            ("Northwind", "prod") => "b",
+        };
+        let wl_input: WLMatrixInput = parse2(input).unwrap();
+
+        let err = check_matrix_reachability(&wl_input.matches).unwrap_err();
+        assert!(err.to_string().contains("duplicate arm"));
+    }
+
+    #[test]
+    fn test_matrix_reachability_rejects_arm_after_full_wildcard() {
+        let input = quote! {
+            axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+            (_, _) => "default",
+            ("Northwind", "prod") => "a",
+        };
+        let wl_input: WLMatrixInput = parse2(input).unwrap();
+
+        let err = check_matrix_reachability(&wl_input.matches).unwrap_err();
+        assert!(err.to_string().contains("unreachable arm"));
+    }
+
+    #[test]
+    fn test_matrix_no_match_error() {
+        let axes = vec!["WHITE_LABEL_BRAND".to_string(), "WHITE_LABEL_TIER".to_string()];
+        let env_values = vec![Some("Acme".to_string()), None];
+
+        let err = no_matrix_arm_matched_error(quote! {}, &axes, &env_values);
+
+        let msg = err.to_string();
+        assert!(msg.contains("WHITE_LABEL_BRAND=Acme"));
+        assert!(msg.contains("WHITE_LABEL_TIER=<unset>"));
+    }
+
+    #[test]
+    fn test_parse_brand_cfg_single() {
+        let input = quote! { "Northwind" };
+        let cfg: WLBrandCfg = parse2(input).unwrap();
+
+        assert!(!cfg.negated);
+        assert_eq!(cfg.brands, vec!["Northwind"]);
+    }
+
+    #[test]
+    fn test_parse_brand_cfg_multiple() {
+        let input = quote! { "Northwind", "Contoso" };
+        let cfg: WLBrandCfg = parse2(input).unwrap();
+
+        assert!(!cfg.negated);
+        assert_eq!(cfg.brands, vec!["Northwind", "Contoso"]);
+    }
+
+    #[test]
+    fn test_parse_brand_cfg_negated() {
+        let input = quote! { not("Northwind") };
+        let cfg: WLBrandCfg = parse2(input).unwrap();
+
+        assert!(cfg.negated);
+        assert_eq!(cfg.brands, vec!["Northwind"]);
+    }
 }
@@ -0,0 +1,79 @@
+//! Integration tests for the `#[brand_cfg(...)]` attribute macro with deterministic
+//! brand selection. These tests compile with `WHITE_LABEL_BRAND="TestBrand"` set by
+//! `build.rs`.
+
+use white_label::brand_cfg;
+
+#[brand_cfg("TestBrand")]
+fn kept_for_matching_brand() -> &'static str {
+    "kept"
+}
+
+#[test]
+fn item_is_kept_when_brand_is_listed() {
+    assert_eq!(kept_for_matching_brand(), "kept");
+}
+
+#[brand_cfg("TestBrand", "Contoso")]
+fn kept_for_one_of_several_brands() -> &'static str {
+    "kept"
+}
+
+#[test]
+fn item_is_kept_when_brand_is_one_of_several() {
+    assert_eq!(kept_for_one_of_several_brands(), "kept");
+}
+
+#[brand_cfg(not("Northwind"))]
+fn kept_unless_excluded_brand() -> &'static str {
+    "kept"
+}
+
+#[test]
+fn item_is_kept_when_negated_brand_does_not_match() {
+    assert_eq!(kept_unless_excluded_brand(), "kept");
+}
+
+#[brand_cfg("TestBrand")]
+struct KeptStruct {
+    value: u32,
+}
+
+#[test]
+fn struct_is_kept_when_brand_matches() {
+    let s = KeptStruct { value: 42 };
+    assert_eq!(s.value, 42);
+}
+
+#[brand_cfg("TestBrand")]
+mod kept_module {
+    pub fn marker() -> &'static str {
+        "module kept"
+    }
+}
+
+#[test]
+fn module_is_kept_when_brand_matches() {
+    assert_eq!(kept_module::marker(), "module kept");
+}
+
+// Compile-time proof that exclusion actually removes an item rather than a `#[test]`
+// that can only observe the surviving half. These two functions share a name and are
+// gated by opposite conditions: if `brand_cfg` ever regressed to always keeping its
+// item (`if keep { item } else { TokenStream::new() }` in src/lib.rs always taking the
+// `keep` branch), this file would fail to compile with a duplicate definition of
+// `exclusive_marker` instead of silently passing.
+#[brand_cfg("TestBrand")]
+fn exclusive_marker() -> &'static str {
+    "kept"
+}
+
+#[brand_cfg(not("TestBrand"))]
+fn exclusive_marker() -> &'static str {
+    "excluded"
+}
+
+#[test]
+fn exclusion_removes_the_non_matching_arm() {
+    assert_eq!(exclusive_marker(), "kept");
+}
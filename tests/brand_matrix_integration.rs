@@ -0,0 +1,70 @@
+//! Integration tests for the `brand_matrix!` macro with deterministic brand/tier selection.
+//! These tests compile with `WHITE_LABEL_BRAND="TestBrand"` and `WHITE_LABEL_TIER="test"`
+//! set by `build.rs`.
+
+use white_label::brand_matrix;
+
+#[test]
+fn matches_full_tuple() {
+    let result = brand_matrix! {
+        axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+        ("TestBrand", "test") => "exact",
+        ("TestBrand", _) => "brand-only",
+        (_, _) => "default",
+    };
+
+    assert_eq!(result, "exact");
+}
+
+#[test]
+fn falls_back_to_brand_only_wildcard() {
+    let result = brand_matrix! {
+        axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+        ("TestBrand", "prod") => "exact",
+        ("TestBrand", _) => "brand-only",
+        (_, _) => "default",
+    };
+
+    assert_eq!(result, "brand-only");
+}
+
+#[test]
+fn falls_back_to_full_wildcard() {
+    let result = brand_matrix! {
+        axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+        ("Northwind", "prod") => "exact",
+        (_, _) => "default",
+    };
+
+    assert_eq!(result, "default");
+}
+
+// note: unlike this, an arm placed *after* a full `(_, _)` catch-all is a compile
+// error (see `check_matrix_reachability`) rather than silently unreachable - a
+// partial wildcard like `("TestBrand", _)` still only shadows arms below it.
+#[test]
+fn first_matching_arm_wins() {
+    let result = brand_matrix! {
+        axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+        ("TestBrand", _) => "brand-only",
+        ("TestBrand", "test") => "exact",
+    };
+
+    assert_eq!(result, "brand-only");
+}
+
+#[cfg(test)]
+mod compile_time_tests {
+    use super::*;
+
+    #[test]
+    fn assign_const() {
+        const VALUE: &str = brand_matrix! {
+            axes: ["WHITE_LABEL_BRAND", "WHITE_LABEL_TIER"],
+            ("TestBrand", "test") => "const_test",
+            (_, _) => "const_default",
+        };
+
+        assert_eq!(VALUE, "const_test");
+    }
+}